@@ -3,12 +3,214 @@
 //! Useful for static values that cannot be initialized through `const fn` but cannot incur the memory & performance cost of a [`std::sync::LazyLock`].
 //!
 //! - See [`static_init!`]
+//! - See [`constructor`] and [`destructor`] for running arbitrary functions before/after `main()`
 //!
 //! # `no_std` support
 //! this crate is `no_std`.
 
+// `static_init!`-generated code refers back to this crate by its published name (e.g.
+// `static_initializer::__teardown::register(..)`) so the same expansion works whether it's
+// invoked from a downstream crate or from right here. An edition 2024 crate can't otherwise name
+// itself that way, so alias it onto its own extern prelude entry.
+extern crate self as static_initializer;
+
 #[doc(inline)]
 pub use static_initializer_macros::static_init;
+#[doc(inline)]
+pub use static_initializer_macros::{constructor, destructor};
+
+/// Support for emulating `priority` ordering of *construction* on Apple targets, where
+/// `__DATA,__mod_init_func` runs its entries in unspecified order. Not part of the public API;
+/// used only by code generated from [`static_init!`].
+///
+/// Destruction order is handled separately and uniformly across all platforms by
+/// [`__teardown`], which tracks the actual order statics finished constructing in rather than
+/// relying on `priority` or linker fini-section order.
+#[doc(hidden)]
+#[cfg(any(target_os = "macos", target_os = "ios"))]
+pub mod __apple_priority {
+    /// One entry in the collected, priority-ordered constructor table.
+    #[repr(C)]
+    #[doc(hidden)]
+    pub struct PriorityEntry {
+        pub priority: u16,
+        pub run: unsafe fn(),
+    }
+
+    // SAFETY: `run` is only ever invoked from the single-threaded master constructor below,
+    // which itself is only ever run once, before `main`.
+    unsafe impl Sync for PriorityEntry {}
+
+    unsafe extern "C" {
+        #[link_name = "section$start$__DATA$__mod_init_order_tbl"]
+        static INIT_TABLE_START: PriorityEntry;
+        #[link_name = "section$end$__DATA$__mod_init_order_tbl"]
+        static INIT_TABLE_END: PriorityEntry;
+    }
+
+    #[doc(hidden)]
+    #[unsafe(link_section = "__DATA,__mod_init_func")]
+    #[used]
+    pub static MASTER_CONSTRUCTOR: unsafe fn() = run_ordered_constructors;
+
+    unsafe fn run_ordered_constructors() {
+        unsafe {
+            // SAFETY: `start`/`end` bracket a contiguous run of `PriorityEntry` values that the
+            // linker collects from every `static_init!` invocation's `__mod_init_order_tbl` entry.
+            let start = &raw const INIT_TABLE_START;
+            let end = &raw const INIT_TABLE_END;
+            let len = end.offset_from(start) as usize;
+            let entries = core::slice::from_raw_parts(start, len);
+
+            // `entries` lives behind a shared `&'static [PriorityEntry]`, not a `static mut` or
+            // `UnsafeCell`, so sorting it in place would be UB even though the linker happens to
+            // place `__DATA` in writable memory. This module has no `alloc` dependency to lean
+            // on either (an Apple target with no global allocator configured would make
+            // collecting into a `Vec` here UB in its own right), so repeatedly scan the slice
+            // itself for the next entry to run instead of sorting a copy of it. O(len^2), but
+            // `len` is the number of `static_init!`/`#[constructor]`/`#[destructor]` items linked
+            // into the whole binary, not runtime data, so it's expected to stay small.
+            let mut last_key: Option<(u16, usize)> = None;
+            for _ in 0..len {
+                // Find the smallest (priority, table index) pair that's still greater than the
+                // last one run. Breaking ties by table index gives entries that share a priority
+                // a well-defined order (first-registered first) instead of leaving it to chance.
+                let mut next: Option<(u16, usize)> = None;
+                for (i, entry) in entries.iter().enumerate() {
+                    let key = (entry.priority, i);
+                    if Some(key) <= last_key {
+                        continue;
+                    }
+                    if next.is_none_or(|n| key < n) {
+                        next = Some(key);
+                    }
+                }
+                let (_, i) = next.expect("one unvisited entry left per remaining iteration");
+                (entries[i].run)();
+                last_key = Some((entries[i].priority, i));
+            }
+        }
+    }
+}
+
+/// A lock-free, allocation-free registry of destructors, ordered by actual construction order
+/// rather than by `priority` or by unspecified linker fini-section order.
+///
+/// Each [`static_init!`] static pushes its own [`Node`] onto this list the moment its
+/// initializer finishes running, so the list's head is always the most recently constructed
+/// static. A single master destructor then walks it front-to-back at shutdown, which is exactly
+/// the reverse of construction order — mirroring the ordering guarantee Rust gives thread-local
+/// destructors. Not part of the public API.
+#[doc(hidden)]
+pub mod __teardown {
+    use core::ptr;
+    use core::sync::atomic::{AtomicPtr, Ordering};
+
+    /// An intrusive teardown-list node. Lives inside the `static_init!`-generated module for the
+    /// static it tears down, so registering it costs no allocation.
+    #[doc(hidden)]
+    pub struct Node {
+        // Only ever read by `run_all`, which is unreachable on the lazy-fallback path (see
+        // `lazy_fallback_enabled` in the proc-macro crate): `MASTER_DESTRUCTOR` below, its only
+        // caller, is cfg'd out there, and nothing in this crate's own build registers a `Node`
+        // either.
+        #[cfg_attr(
+            any(
+                feature = "lazy-fallback",
+                not(any(
+                    target_os = "windows",
+                    target_os = "macos",
+                    target_os = "ios",
+                    target_os = "linux",
+                    target_os = "android",
+                )),
+            ),
+            allow(dead_code)
+        )]
+        deinit: unsafe fn(),
+        next: AtomicPtr<Node>,
+    }
+
+    // SAFETY: `deinit` is only ever invoked once, from the single-threaded master destructor,
+    // after every static has either finished constructing or never started.
+    unsafe impl Sync for Node {}
+
+    impl Node {
+        #[doc(hidden)]
+        pub const fn new(deinit: unsafe fn()) -> Self {
+            Node {
+                deinit,
+                next: AtomicPtr::new(ptr::null_mut()),
+            }
+        }
+    }
+
+    static HEAD: AtomicPtr<Node> = AtomicPtr::new(ptr::null_mut());
+
+    /// Pushes `node` to the front of the teardown list, making it the next static torn down.
+    ///
+    /// # Safety
+    /// `node` must not already be registered.
+    #[doc(hidden)]
+    pub unsafe fn register(node: &'static Node) {
+        let node_ptr = node as *const Node as *mut Node;
+        let mut current = HEAD.load(Ordering::Relaxed);
+        loop {
+            node.next.store(current, Ordering::Relaxed);
+            match HEAD.compare_exchange_weak(current, node_ptr, Ordering::Release, Ordering::Relaxed) {
+                Ok(_) => break,
+                Err(actual) => current = actual,
+            }
+        }
+    }
+
+    // Unreferenced on the lazy-fallback path, since `MASTER_DESTRUCTOR` below doesn't exist there
+    // either; see the matching note on `Node::deinit`.
+    #[cfg_attr(
+        any(
+            feature = "lazy-fallback",
+            not(any(
+                target_os = "windows",
+                target_os = "macos",
+                target_os = "ios",
+                target_os = "linux",
+                target_os = "android",
+            )),
+        ),
+        allow(dead_code)
+    )]
+    unsafe fn run_all() {
+        unsafe {
+            let mut current = HEAD.load(Ordering::Acquire);
+            while !current.is_null() {
+                let node = &*current;
+                (node.deinit)();
+                current = node.next.load(Ordering::Relaxed);
+            }
+        }
+    }
+
+    // The single master destructor for the whole binary: one entry in each platform's fini
+    // section, rather than one per `static_init!` static, so the exact reverse of construction
+    // order (tracked above) is what decides teardown order, not the order the linker happens to
+    // place individual fini entries in.
+    #[cfg(all(
+        any(
+            target_os = "windows",
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "linux",
+            target_os = "android",
+        ),
+        not(feature = "lazy-fallback"),
+    ))]
+    #[doc(hidden)]
+    #[cfg_attr(target_os = "windows", unsafe(link_section = ".CRT$XPTZ65535"))]
+    #[cfg_attr(any(target_os = "macos", target_os = "ios"), unsafe(link_section = "__DATA,__mod_term_func"))]
+    #[cfg_attr(any(target_os = "linux", target_os = "android"), unsafe(link_section = ".fini_array.65535"))]
+    #[used]
+    static MASTER_DESTRUCTOR: unsafe fn() = run_all;
+}
 
 #[cfg(test)]
 mod tests {
@@ -18,9 +220,94 @@ mod tests {
         static TEST: Vec<u8> = unsafe static { (0..15u8).collect() };
     }
 
+    static_init! {
+        priority = 10;
+        static PRIORITIZED: u32 = unsafe static { 42 };
+    }
+
+    #[test]
+    fn priority_static_is_initialized() {
+        assert_eq!(*PRIORITIZED, 42);
+    }
+
+    #[test]
+    fn try_get_sees_an_initialized_static() {
+        // By the time a test runs, the constructor has already run.
+        let expected: Vec<u8> = (0..15u8).collect();
+        assert_eq!(TEST.try_get(), Some(&expected));
+    }
+
     #[test]
     fn it_works() {
         // Should cause UB if something is weird
         println!("Test vec: {:?}", TEST.as_slice());
     }
+
+    #[constructor]
+    fn ctor_test() {
+        println!("constructor ran");
+    }
+
+    #[destructor]
+    fn dtor_test() {
+        println!("destructor ran");
+    }
+
+    #[test]
+    fn ctor_and_dtor_are_callable() {
+        // Exercises the generated functions directly; the link-section registration
+        // itself is verified by running the actual before/after `main` behavior.
+        ctor_test();
+        dtor_test();
+    }
+
+    // Only meaningful under `lazy-fallback`: without it, every static above has already finished
+    // initializing (via its real before-`main` constructor) long before any test body runs, so
+    // there's no first-touch race window left to exercise.
+    #[cfg(feature = "lazy-fallback")]
+    static_init! {
+        static SLOW: u32 = unsafe static {
+            std::thread::sleep(std::time::Duration::from_millis(20));
+            7
+        };
+    }
+
+    #[cfg(feature = "lazy-fallback")]
+    #[test]
+    fn concurrent_first_touch_races_instead_of_panicking() {
+        // Several threads touching a still-uninitialized lazy static at once used to be
+        // misdiagnosed as a dependency cycle (the losing threads saw `STATE == RUNNING` and
+        // panicked, since a shared flag can't tell "another thread got there first" apart from
+        // "this thread is already in the middle of this very initializer"). They should instead
+        // block until the winning thread finishes, and every thread should observe the same,
+        // single initialization.
+        let handles: Vec<_> = (0..8)
+            .map(|_| std::thread::spawn(|| *SLOW))
+            .collect();
+        for handle in handles {
+            assert_eq!(handle.join().expect("thread should not panic"), 7);
+        }
+    }
+
+    #[cfg(feature = "lazy-fallback")]
+    static_init! {
+        static POISONS: u32 = unsafe static {
+            std::thread::sleep(std::time::Duration::from_millis(20));
+            panic!("boom");
+        };
+    }
+
+    #[cfg(feature = "lazy-fallback")]
+    #[test]
+    fn waiting_thread_panics_instead_of_hanging_when_initializer_panics() {
+        // A thread that loses the race and starts waiting must not spin forever if the winning
+        // thread's initializer panics instead of finishing: that would leave `STATE` stuck at
+        // `RUNNING` forever with nothing left to ever move it out.
+        let handles: Vec<_> = (0..4)
+            .map(|_| std::thread::spawn(|| *POISONS))
+            .collect();
+        for handle in handles {
+            assert!(handle.join().is_err(), "every thread should observe a panic, not hang");
+        }
+    }
 }
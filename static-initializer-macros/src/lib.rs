@@ -1,6 +1,6 @@
 use proc_macro::TokenStream;
 use quote::{format_ident, quote, quote_spanned};
-use syn::{ExprBlock, Ident, LitStr, parse_macro_input, Token, Type, Visibility};
+use syn::{ExprBlock, Ident, ItemFn, LitInt, LitStr, parse_macro_input, Token, Type, Visibility};
 use syn::__private::{Span, TokenStream2};
 use syn::parse::{Parse, ParseStream};
 use syn::spanned::Spanned;
@@ -10,10 +10,23 @@ struct StaticWithInitializer {
     name: Ident,
     ty: Type,
     init: ExprBlock,
+    priority: u16,
 }
 
 impl Parse for StaticWithInitializer {
     fn parse(input: ParseStream) -> syn::parse::Result<Self> {
+        // Optional `priority = <u16>;` line controlling construction/destruction order.
+        // Defaults to `u16::MAX`, i.e. "run last" (mirroring the previous hardcoded behavior).
+        let priority: u16 = if input.peek(Ident) && input.fork().parse::<Ident>()? == "priority" {
+            input.parse::<Ident>()?;
+            input.parse::<Token![=]>()?;
+            let lit: LitInt = input.parse()?;
+            input.parse::<Token![;]>()?;
+            lit.base10_parse()?
+        } else {
+            u16::MAX
+        };
+
         let vis: Visibility = input.parse()?;
         input.parse::<Token![static]>()?;
         let name: Ident = input.parse()?;
@@ -29,6 +42,7 @@ impl Parse for StaticWithInitializer {
             name,
             ty,
             init,
+            priority,
         })
     }
 }
@@ -60,7 +74,39 @@ fn cfg_unsupported() -> TokenStream2 {
     )
 }
 
-fn get_initializer_attributes(priority: u16) -> TokenStream2 {
+/// Whether *this* crate's `lazy-fallback` feature is enabled, i.e. fallback was explicitly
+/// requested rather than auto-detected from the target.
+///
+/// This is deliberately a plain `bool` computed once at macro-expansion time via `cfg!`, not a
+/// `#[cfg(feature = "lazy-fallback")]` token spliced into the generated code: the generated code
+/// is compiled as part of the *caller's* crate, so a `feature = "lazy-fallback"` token embedded
+/// in it would check the caller's own (almost certainly nonexistent) feature of that name instead
+/// of this crate's — silently doing nothing, and spamming every caller with an `unexpected_cfgs`
+/// warning to boot. `static-initializer`'s own `lazy-fallback` feature forwards to this crate's
+/// identically named one (see its Cargo.toml), so `cfg!` here sees it correctly.
+fn lazy_fallback_enabled() -> bool {
+    cfg!(feature = "lazy-fallback")
+}
+
+/// Whether *this* crate's `checked` feature is enabled. See [`lazy_fallback_enabled`] for why
+/// this has to be a plain `bool` read via `cfg!` here rather than a `#[cfg(feature = "checked")]`
+/// token emitted into the generated code.
+fn checked_enabled() -> bool {
+    cfg!(feature = "checked")
+}
+
+/// Attributes that place a plain fn pointer directly into the constructor section of each
+/// platform. `apple_direct` controls whether macOS/iOS also gets a direct placement into
+/// `__DATA,__mod_init_func`: callers that need Apple ordering (see [`get_apple_priority_entry`])
+/// register into the collected priority table instead and pass `false` here so the fn pointer
+/// isn't *also* run directly and unordered.
+fn get_initializer_attributes(priority: u16, apple_direct: bool) -> TokenStream2 {
+    // `lazy-fallback` forces fallback mode unconditionally, on every target, so it's decided here
+    // rather than folded into the `#[cfg(...)]` tokens below (see `lazy_fallback_enabled`).
+    if lazy_fallback_enabled() {
+        return quote!();
+    }
+
     let win = cfg_windows();
     let apple = cfg_apple();
     let unix = cfg_unix();
@@ -70,18 +116,27 @@ fn get_initializer_attributes(priority: u16) -> TokenStream2 {
     let apple_sections = LitStr::new("__DATA,__mod_init_func", Span::call_site());
     let unix_sections = LitStr::new(format!(".init_array.{:05}", priority).as_str(), Span::call_site());
 
+    let apple_attr = if apple_direct {
+        quote!(#[cfg_attr(not(#unsupported), cfg_attr(#apple, unsafe(link_section = #apple_sections)))])
+    } else {
+        quote!()
+    };
+
     quote!(
-        #[cfg(#unsupported)]
-        compiler_error!("Unsupported Target OS!");
-        // Linker Magic!
-        #[cfg_attr(#win, unsafe(link_section = #win_sections))]
-        #[cfg_attr(#apple, unsafe(link_section = #apple_sections))]
-        #[cfg_attr(#unix, unsafe(link_section = #unix_sections))]
+        // Linker Magic! Skipped entirely on targets with no such section at all.
+        #[cfg_attr(not(#unsupported), cfg_attr(#win, unsafe(link_section = #win_sections)))]
+        #apple_attr
+        #[cfg_attr(not(#unsupported), cfg_attr(#unix, unsafe(link_section = #unix_sections)))]
     )
 
 }
 
-fn get_deinitializer_attributes(priority: u16) -> TokenStream2 {
+/// See [`get_initializer_attributes`]; the destructor-side counterpart.
+fn get_deinitializer_attributes(priority: u16, apple_direct: bool) -> TokenStream2 {
+    if lazy_fallback_enabled() {
+        return quote!();
+    }
+
     let win = cfg_windows();
     let apple = cfg_apple();
     let unix = cfg_unix();
@@ -91,17 +146,54 @@ fn get_deinitializer_attributes(priority: u16) -> TokenStream2 {
     let apple_sections = LitStr::new("__DATA,__mod_term_func", Span::call_site());
     let unix_sections = LitStr::new(format!(".fini_array.{:05}", priority).as_str(), Span::call_site());
 
+    let apple_attr = if apple_direct {
+        quote!(#[cfg_attr(not(#unsupported), cfg_attr(#apple, unsafe(link_section = #apple_sections)))])
+    } else {
+        quote!()
+    };
+
     quote!(
-        #[cfg(#unsupported)]
-        compiler_error!("Unsupported Target OS!");
-        // Linker Magic!
-        #[cfg_attr(#win, unsafe(link_section = #win_sections))]
-        #[cfg_attr(#apple, unsafe(link_section = #apple_sections))]
-        #[cfg_attr(#unix, unsafe(link_section = #unix_sections))]
+        // Linker Magic! Skipped entirely on targets with no such section at all (see
+        // `get_initializer_attributes`).
+        #[cfg_attr(not(#unsupported), cfg_attr(#win, unsafe(link_section = #win_sections)))]
+        #apple_attr
+        #[cfg_attr(not(#unsupported), cfg_attr(#unix, unsafe(link_section = #unix_sections)))]
     )
 
 }
 
+/// Registers `run` into the shared, priority-ordered constructor table used to emulate
+/// `priority` ordering of construction on Apple targets, where `__DATA,__mod_init_func` offers no
+/// ordering guarantee of its own. A single master constructor (see
+/// `static_initializer::__apple_priority`) scans this table, sorts it by priority, and runs each
+/// entry in that order. `kind` is threaded through so the same helper can (in principle) back
+/// more than one such table; `static_init!` only ever uses `"init"` today, since destruction
+/// order is handled uniformly across all platforms by `static_initializer::__teardown` instead.
+fn get_apple_priority_entry(kind: &str, name: &Ident, priority: u16, run: &Ident) -> TokenStream2 {
+    if lazy_fallback_enabled() {
+        return quote!();
+    }
+
+    let apple = cfg_apple();
+    let table_section = LitStr::new(
+        format!("__DATA,__mod_{}_order_tbl", kind).as_str(),
+        Span::call_site(),
+    );
+    let entry_ident = format_ident!("__static_init_apple_{}_entry_n{}", kind, name.to_string().to_lowercase());
+
+    quote!(
+        #[cfg(#apple)]
+        #[used]
+        #[doc(hidden)]
+        #[unsafe(link_section = #table_section)]
+        static #entry_ident: static_initializer::__apple_priority::PriorityEntry =
+            static_initializer::__apple_priority::PriorityEntry {
+                priority: #priority,
+                run: #run,
+            };
+    )
+}
+
 fn get_module_ident(var: &Ident) -> Ident {
     format_ident!("__static_init_module_n{}", var.to_string().to_lowercase())
 }
@@ -111,20 +203,43 @@ fn get_module_ident(var: &Ident) -> Ident {
 /// # Syntax
 /// > **<sup>Syntax</sup>**\
 /// > _StaticItemWithInitializer_ :\
-/// > &nbsp;&nbsp; `static_init!` { [Visibility](https://doc.rust-lang.org/reference/visibility-and-privacy.html)<sup>?</sup> `static` [Identifier](https://doc.rust-lang.org/reference/identifiers.html) `:` [Type](https://doc.rust-lang.org/reference/types.html#type-expressions)
-/// >              ( `=` `unsafe` `static` [Block](https://doc.rust-lang.org/reference/expressions/block-expr.html) ) `;` }
+/// > &nbsp;&nbsp; ( `priority` `=` [IntegerLiteral](https://doc.rust-lang.org/reference/tokens.html#integer-literals) `;` )<sup>?</sup>\
+/// > &nbsp;&nbsp; [Visibility](https://doc.rust-lang.org/reference/visibility-and-privacy.html)<sup>?</sup> `static` [Identifier](https://doc.rust-lang.org/reference/identifiers.html) `:` [Type](https://doc.rust-lang.org/reference/types.html#type-expressions)
+/// >              ( `=` `unsafe` `static` [Block](https://doc.rust-lang.org/reference/expressions/block-expr.html) ) `;`
 /// >
 ///
+/// The optional leading `priority = <u16>;` controls construction/destruction order relative to
+/// other `static_init!` statics: lower priorities run first during construction and last during
+/// destruction, mirroring C's `__attribute__((constructor(priority)))`. It defaults to
+/// `u16::MAX`, i.e. "run last".
 /// # Undefined Behavior
 /// *This macro may cause undefined behavior if:
 /// - the initializer creates a new thread
-/// - the initializer references other statics created with this macro
 /// - the initializer references the static it is initializing (In violation of rust's aliasing rules)
 /// - [`std::sync::mpmc`](https://doc.rust-lang.org/std/sync/mpmc/index.html) or [`std::sync::mpsc`](https://doc.rust-lang.org/std/sync/mpsc/index.html) is used
 /// - See [Use before and after main](https://doc.rust-lang.org/std/#use-before-and-after-main)
 ///
 /// For this reason, the unsafe keyword is required to declare initializers with this macro.
 /// In the future these scenarios will hopefully become compile errors, and the unsafe keyword will no longer be required.
+///
+/// # Referencing other `static_init!` statics
+/// Because link-section init order is unspecified, an initializer used to be unable to safely
+/// reference another static declared with this macro. Each generated static now carries a small
+/// init-state guard, so dereferencing a sibling `static_init!` static from inside an initializer
+/// pulls that sibling's initializer in on demand, whichever static happens to run first. A static
+/// that (directly or transitively) references itself *from the same thread* while initializing is
+/// a dependency cycle and will `panic!` rather than produce UB. This only matters once a static
+/// can be reached from more than one thread in the first place (e.g. under `lazy-fallback`, where
+/// `Deref` is the only trigger): if a different thread reaches the guard while the first is still
+/// running the initializer, it isn't a cycle, just a race, and blocks until the first thread
+/// finishes instead of panicking.
+/// # Probing readiness with `try_get`
+/// `Deref` always succeeds once the static has finished initializing, and on native backends
+/// that's true for all of `main()`. But code that might run earlier — another constructor, or a
+/// signal handler — can't assume that, and `Deref` would either pull the static's initializer in
+/// on demand (see above) or, if it's already torn down, `panic!`. Use the generated static's
+/// `try_get()` method instead to probe: it returns `Some(&value)` once initialized and not yet
+/// torn down, `None` otherwise, without running the initializer or panicking either way.
 /// # Examples
 /// ```rust
 /// use static_initializer::static_init;
@@ -139,19 +254,26 @@ fn get_module_ident(var: &Ident) -> Ident {
 /// }
 /// ```
 /// # Compatibility
-/// This macro only works on certain operating systems due to the fact that it uses link sections to run code before `main()`
-/// All major operating systems are supported, and more may be supported in the future.
-/// `wasm` is currently not supported.
+/// This macro uses link sections to run code before `main()` on Windows, macOS/iOS, Linux and
+/// Android. On any other target (including `wasm32-unknown-unknown` and bare-metal), or whenever
+/// the `lazy-fallback` feature is enabled, there is no such section to register into, so the
+/// static degrades to first-touch initialization instead: the first `Deref` access runs the
+/// initializer. This keeps the same `static_init!` source compiling everywhere, at the cost of
+/// losing the true before-`main` guarantee (and the matching after-`main` destructor call) on
+/// those targets.
 /// # Under the hood
-/// Internally this macro uses the `#[link_section]` attribute in order to have initializers and deinitializers run before and after `main()`
+/// Internally this macro uses the `#[link_section]` attribute in order to have initializers run before `main()`.
+///
+/// On windows the link section used is `.CRT$XCU<5 digit priority number>`.
 ///
-/// On windows the link section used is `.CRT$XCU<5 digit priority number>` for constructors and `.CRT$XPTZ<5 digit priority number>` for destructors.
+/// On macOS and ios, `__DATA,__mod_init_func` is used for placement, but since that section carries no ordering guarantee of its own, `priority` is emulated: each static registers a `(priority, fn ptr)` record into a separate, linker-collected table instead of running directly, and a single master constructor sorts that table by priority and invokes the entries in order.
 ///
-/// On macOS and ios, `__DATA,__mod_init_func` and `__DATA,__mod_term_func` are used.
+/// On linux and other Unix-based operating systems, `.init_array.<5 digit priority number>` is used.
 ///
-/// On linux and other Unix-based operating systems, `.init_array.<5 digit priority number>` and `.fini_array.<5 digit priority number>` are used.
+/// Note `<5 digit priority number>` is replaced with a 5 digit base-10 formatted number ranging from `0` to [`u16::MAX`] which represents the order in which the initializers are run.
 ///
-/// Note `<5 digit priority number>` is replaced with a 5 digit base-10 formatted number ranging from `0` to [`u16::MAX`] which represents the order in which the initializers are run. Priority is not currently used and is not supported on some operating systems.
+/// # Teardown order
+/// Destruction does not use `priority`, and does not rely on `.fini_array`/`__mod_term_func`/`.CRT$XPTZ` running their entries in any particular order (they don't guarantee one). Instead, each static registers itself with a small lock-free registry the moment its own initializer finishes, recording the actual order statics were constructed in. A single master destructor then walks that registry and tears every static down in the exact reverse of that order — the same guarantee Rust gives thread-local destructors.
 #[proc_macro]
 pub fn static_init(item: TokenStream) -> TokenStream {
     let StaticWithInitializer {
@@ -159,6 +281,7 @@ pub fn static_init(item: TokenStream) -> TokenStream {
         name,
         ty,
         init,
+        priority,
     } = parse_macro_input!(item as StaticWithInitializer);
 
     // usual assertions for static
@@ -170,9 +293,77 @@ pub fn static_init(item: TokenStream) -> TokenStream {
     };
 
     let module_name = get_module_ident(&name);
-    let priority: u16 = 65535;
-    let init_attributes = get_initializer_attributes(priority);
-    let deinit_attributes = get_deinitializer_attributes(priority);
+    // Apple targets have no ordered init section, so construction ordering is emulated via a
+    // separately collected priority table (see `get_apple_priority_entry`) instead of a direct,
+    // unordered placement into `__DATA,__mod_init_func`. Destruction order is handled uniformly
+    // across all platforms by the teardown registry below, so there is no destructor-side
+    // equivalent of this table (or of `_D`/`deinit_attributes`) any more.
+    let init_attributes = get_initializer_attributes(priority, false);
+    let init_priority_entry = get_apple_priority_entry("init", &name, priority, &format_ident!("ensure_init"));
+    // In fallback mode nothing ever runs a destructor (see `lazy_fallback_enabled`), so
+    // registering with the teardown registry would just be dead weight on every access; skip
+    // it entirely. Unsupported targets (no init-array-style section at all) skip it too, gated
+    // by `cfg_unsupported` rather than decided here, since that's a property of the *caller's*
+    // actual compile target and has to be checked at the caller's compile time.
+    let unsupported = cfg_unsupported();
+    let lazy_fallback = lazy_fallback_enabled();
+    let teardown_decl = if lazy_fallback {
+        quote!()
+    } else {
+        quote!(
+            #[doc(hidden)]
+            #[cfg(not(#unsupported))]
+            unsafe fn deinit() {
+                // SAFETY: this is only called when the program exits
+                unsafe {
+                    (&mut *(&raw mut INTERNAL)).assume_init_drop();
+                }
+                STATE.store(TORN_DOWN, core::sync::atomic::Ordering::Release);
+            }
+
+            // Registers `deinit` with the global teardown registry the moment `init` finishes,
+            // so destruction always happens in the exact reverse of actual construction order
+            // regardless of `priority` or of where the linker happens to place fini entries.
+            #[doc(hidden)]
+            #[cfg(not(#unsupported))]
+            static TEARDOWN_NODE: static_initializer::__teardown::Node =
+                static_initializer::__teardown::Node::new(deinit);
+        )
+    };
+    let register_call = if lazy_fallback {
+        quote!()
+    } else {
+        quote!(
+            #[cfg(not(#unsupported))]
+            static_initializer::__teardown::register(&TEARDOWN_NODE);
+        )
+    };
+
+    // Belt-and-suspenders check in checked builds: `ensure_init` above should make this
+    // unreachable, but this is the same `try_get`-style flag check, so if it ever isn't, fail
+    // loudly instead of reading uninitialized memory. Always included under `debug_assertions`
+    // (a profile flag, so emitting it as a token and letting the caller's own build resolve it
+    // is correct); additionally forced on in release builds when *this* crate's `checked`
+    // feature is enabled, decided here rather than via a `feature = "checked"` token for the
+    // same reason `lazy_fallback_enabled` is (see its doc comment).
+    let checked_check = {
+        let panic_body = quote!(
+            if !#module_name::is_initialized() {
+                panic!(
+                    "static_init! static `{}` accessed before initialization or after teardown",
+                    stringify!(#name),
+                );
+            }
+        );
+        if checked_enabled() {
+            panic_body
+        } else {
+            quote!(
+                #[cfg(debug_assertions)]
+                #panic_body
+            )
+        }
+    };
 
     let expanded = quote! {
         #vis struct #name;
@@ -186,8 +377,24 @@ pub fn static_init(item: TokenStream) -> TokenStream {
 
             static mut INTERNAL: core::mem::MaybeUninit<#ty> = core::mem::MaybeUninit::uninit();
 
+            const UNINIT: u8 = 0;
+            const RUNNING: u8 = 1;
+            const DONE: u8 = 2;
+            const TORN_DOWN: u8 = 3;
+            const POISONED: u8 = 4;
+
+            // Tracks init progress so that sibling `static_init!` statics can be
+            // safely referenced from within this one's initializer: whichever
+            // static runs first pulls its dependencies in on demand. Also tracks
+            // teardown, so a post-destructor access is a clear panic instead of UB.
+            static STATE: core::sync::atomic::AtomicU8 = core::sync::atomic::AtomicU8::new(UNINIT);
+
             #[doc(hidden)]
             #[allow(unused_braces)]
+            // An initializer that unconditionally diverges (panics, loops forever,
+            // `process::exit`s) is unusual but not wrong; `#init` is user-supplied, so the call
+            // that evaluates it shouldn't warn just because it never returns.
+            #[allow(unreachable_code)]
             unsafe fn init() {
                 // SAFETY: this is the only place where it can be accessed mutably
                 unsafe {
@@ -195,12 +402,97 @@ pub fn static_init(item: TokenStream) -> TokenStream {
                 }
             }
 
+            #teardown_decl
+
+            // Set on this thread only while this static's own initializer is running, so
+            // `ensure_init` can tell "another thread is racing us" (STATE is RUNNING, but this
+            // thread didn't set it — not a cycle, just contention to wait out) apart from "this
+            // thread got back here while already inside its own initializer" (a genuine
+            // dependency cycle). A shared flag can't make that distinction on its own: `STATE ==
+            // RUNNING` looks identical from every thread's point of view.
+            std::thread_local! {
+                static IN_PROGRESS: core::cell::Cell<bool> = const { core::cell::Cell::new(false) };
+            }
+
+            use core::sync::atomic::Ordering;
+
+            // Flips `STATE` to `POISONED` if `init` unwinds, so threads waiting on this static
+            // (see the `RUNNING` case in `ensure_init` below) get a clear panic of their own
+            // instead of spinning on a `RUNNING` state nothing will ever move out of again.
+            struct PoisonOnUnwind;
+            impl Drop for PoisonOnUnwind {
+                fn drop(&mut self) {
+                    if std::thread::panicking() {
+                        STATE.store(POISONED, Ordering::Release);
+                    }
+                }
+            }
+
+            /// Runs `init` exactly once, even if reached both from this static's
+            /// own constructor and from another `static_init!` static's initializer, blocking
+            /// any other thread that reaches it while initialization is still in progress.
+            ///
+            /// # Panics
+            /// Panics on a dependency cycle, if the initializer has already panicked once, or if
+            /// called after `deinit` has already run.
             #[doc(hidden)]
-            unsafe fn deinit() {
-                // SAFETY: this is only called when the program exits
-                unsafe {
-                    (&mut *(&raw mut INTERNAL)).assume_init_drop();
+            pub unsafe fn ensure_init() {
+                if STATE.compare_exchange(UNINIT, RUNNING, Ordering::Acquire, Ordering::Acquire).is_ok() {
+                    IN_PROGRESS.with(|f| f.set(true));
+                    let poison_guard = PoisonOnUnwind;
+                    // SAFETY: we just won the UNINIT -> RUNNING transition, so
+                    // we are the only caller allowed to run the initializer
+                    unsafe {
+                        init();
+                        #register_call
+                    }
+                    core::mem::forget(poison_guard);
+                    IN_PROGRESS.with(|f| f.set(false));
+                    STATE.store(DONE, Ordering::Release);
+                    return;
                 }
+
+                // Either already finished, already torn down, poisoned, or another thread is
+                // mid-initializer: re-read (rather than retry the compare_exchange above) so a
+                // losing thread just spins on a cheap load instead of repeatedly taking the
+                // shared cache line exclusive with a doomed-to-fail atomic RMW.
+                loop {
+                    match STATE.load(Ordering::Acquire) {
+                        DONE => return,
+                        TORN_DOWN => {
+                            panic!(
+                                "static_init! static `{}` accessed after its destructor has already run",
+                                stringify!(#name),
+                            );
+                        }
+                        POISONED => {
+                            panic!(
+                                "static_init! static `{}` can't be used: its initializer panicked",
+                                stringify!(#name),
+                            );
+                        }
+                        RUNNING => {
+                            if IN_PROGRESS.with(|f| f.get()) {
+                                panic!(
+                                    "static_init! dependency cycle detected while initializing `{}`",
+                                    stringify!(#name),
+                                );
+                            }
+                            // Some other thread is running the initializer; wait for it to
+                            // finish (or poison, or tear the static down) rather than treating
+                            // its presence as a cycle.
+                            core::hint::spin_loop();
+                        }
+                        _ => unreachable!(
+                            "STATE only ever holds UNINIT, RUNNING, DONE, TORN_DOWN or POISONED"
+                        ),
+                    }
+                }
+            }
+
+            #[doc(hidden)]
+            pub fn is_initialized() -> bool {
+                STATE.load(core::sync::atomic::Ordering::Acquire) == DONE
             }
 
             #[doc(hidden)]
@@ -208,17 +500,33 @@ pub fn static_init(item: TokenStream) -> TokenStream {
                 &raw const INTERNAL
             }
 
-            // Add initializer fn pointers to the initializer array
+            // Add the initializer fn pointer to the initializer array
             #init_attributes
             #[used]
             #[doc(hidden)]
-            static _I: unsafe fn() -> () = init;
+            static _I: unsafe fn() -> () = ensure_init;
 
-            #deinit_attributes
-            #[used]
-            #[doc(hidden)]
-            static _D: unsafe fn() -> () = deinit;
+            // On Apple targets, construction `priority` ordering is emulated through a shared
+            // table instead of the (unordered) direct placement above.
+            #init_priority_entry
+        }
 
+        #[doc(hidden)]
+        impl #name {
+            /// Returns the value if this static has finished initializing and hasn't yet been
+            /// torn down, or `None` otherwise, instead of the `panic!`/on-demand-init behavior
+            /// of [`Deref`](std::ops::Deref). Useful for probing readiness from a context that
+            /// might run before this static's constructor does, such as another constructor or
+            /// a signal handler.
+            pub fn try_get(&self) -> Option<&#ty> {
+                if #module_name::is_initialized() {
+                    // SAFETY: `is_initialized` means `init` has written `INTERNAL` and `deinit`
+                    // has not yet run
+                    Some(unsafe { (&*#module_name::get_raw()).assume_init_ref() })
+                } else {
+                    None
+                }
+            }
         }
 
         #[doc(hidden)]
@@ -226,14 +534,117 @@ pub fn static_init(item: TokenStream) -> TokenStream {
             type Target = #ty;
 
             fn deref(&self) -> &Self::Target {
-
-                // SAFETY: initialized at the top of main
+                // SAFETY: `ensure_init` guarantees `INTERNAL` has been written, whether
+                // this is the first touch before `main` or a reference pulled in from
+                // another `static_init!` static's initializer; it panics outright on a
+                // dependency cycle or a post-teardown access rather than let either through.
                 unsafe {
+                    #module_name ::ensure_init();
+
+                    #checked_check
+
                     (&*#module_name ::get_raw()).assume_init_ref()
                 }
             }
         }
     };
 
-    return TokenStream::from(expanded);
+    TokenStream::from(expanded)
+}
+
+fn get_registration_ident(kind: &str, func: &Ident) -> Ident {
+    format_ident!("__static_init_{}_n{}", kind, func.to_string().to_lowercase())
+}
+
+/// Runs a plain `fn()` before `main()` is called, the same way `#[destructor]` runs one after.
+/// This is a portable equivalent of C's `__attribute__((constructor))`, for one-off setup
+/// (registering allocators, installing panic hooks, warming caches) that doesn't need a fake
+/// [`static_init!`] static to hang off of.
+///
+/// Reuses the same cross-platform link-section placement as [`static_init!`]: see its
+/// "Under the hood" and "Compatibility" sections for exactly which section is used per platform.
+/// Unlike `static_init!`, there's no `Deref` (or anything else) to fall back to triggering lazily:
+/// on a target with no such section at all, or whenever the `lazy-fallback` feature is enabled,
+/// `#[constructor]`/`#[destructor]` become no-ops instead — the function is simply never called.
+///
+/// # Examples
+/// ```rust
+/// use static_initializer::constructor;
+///
+/// #[constructor]
+/// fn setup() {
+///     println!("running before main");
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn constructor(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    let func = parse_macro_input!(item as ItemFn);
+    let fn_name = &func.sig.ident;
+
+    let priority: u16 = 65535;
+    let init_attributes = get_initializer_attributes(priority, true);
+    let trigger_ident = get_registration_ident("ctor_trigger", fn_name);
+    let static_ident = get_registration_ident("ctor", fn_name);
+
+    let expanded = quote! {
+        #func
+
+        #[doc(hidden)]
+        unsafe fn #trigger_ident() {
+            // SAFETY: called at most once, before main. On a target/feature combination where
+            // `#init_attributes` places no link section at all, this trigger is simply never
+            // referenced from anywhere and `#fn_name` never runs; see `constructor`'s doc comment.
+            #fn_name();
+        }
+
+        #init_attributes
+        #[used]
+        #[doc(hidden)]
+        static #static_ident: unsafe fn() -> () = #trigger_ident;
+    };
+
+    TokenStream::from(expanded)
+}
+
+/// Runs a plain `fn()` after `main()` returns, the same way `#[constructor]` runs one before.
+/// See [`constructor`] for details; this is the teardown counterpart.
+///
+/// # Examples
+/// ```rust
+/// use static_initializer::destructor;
+///
+/// #[destructor]
+/// fn teardown() {
+///     println!("running after main");
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn destructor(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    let func = parse_macro_input!(item as ItemFn);
+    let fn_name = &func.sig.ident;
+
+    let priority: u16 = 65535;
+    let deinit_attributes = get_deinitializer_attributes(priority, true);
+    let trigger_ident = get_registration_ident("dtor_trigger", fn_name);
+    let static_ident = get_registration_ident("dtor", fn_name);
+
+    let expanded = quote! {
+        #func
+
+        #[doc(hidden)]
+        unsafe fn #trigger_ident() {
+            // SAFETY: called at most once, after main returns. On a target/feature combination
+            // where `#deinit_attributes` places no link section at all, this trigger is simply
+            // never referenced from anywhere and `#fn_name` never runs; see `constructor`'s doc
+            // comment.
+            #fn_name();
+        }
+
+        #deinit_attributes
+        #[used]
+        #[doc(hidden)]
+        static #static_ident: unsafe fn() -> () = #trigger_ident;
+    };
+
+    TokenStream::from(expanded)
 }